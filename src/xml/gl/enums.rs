@@ -62,22 +62,49 @@ impl core::fmt::Display for EnumDisplay<'_> {
   }
 }
 
-/// A map of enum keys to values
+/// A map of enum keys to values, plus the `alias="..."` relationships
+/// discovered while parsing.
 #[derive(Debug, Default, Clone)]
-pub struct Enums(pub(crate) HashMap<EnumKey, EnumValue>);
+pub struct Enums {
+  pub(crate) map: HashMap<EnumKey, EnumValue>,
+  /// Resolved aliases, keyed by the aliasing name and pointing at the
+  /// canonical name it stands for (eg: `GL_ACTIVE_PROGRAM_EXT` ->
+  /// `GL_ACTIVE_PROGRAM`). Use [`display_alias`] at emit time to turn an
+  /// entry into a reference rather than a duplicated literal.
+  pub aliases: HashMap<String, String>,
+}
+
+/// An insertion-ordered collection of a group's member names.
+///
+/// A plain `HashSet<String>` doesn't preserve the order members were
+/// declared in the registry XML. Generating a dense, array-backed
+/// [`EnumMapDisplay`] needs a fixed, meaningful order to assign stable array
+/// indices, so groups are collected into this instead: a thin `Vec` wrapper
+/// that dedups on insert like a set would.
+#[derive(Debug, Default, Clone)]
+pub struct OrderedGroup(pub Vec<String>);
+impl OrderedGroup {
+  /// Inserts `name` if it isn't already present, preserving prior order.
+  pub fn insert(&mut self, name: String) {
+    if !self.0.contains(&name) {
+      self.0.push(name);
+    }
+  }
+}
 
 /// Grabs an `enums` tag from the iterator.
 ///
 /// * `is_bitmask`: This is part of the tag attributes, you provide it.
 /// * `group`: Also a tag attribute. If you provide a reference here all enums
-///   collected for this tag will also be added into the group.
+///   collected for this tag will also be added into the group, in the order
+///   they're declared.
 #[must_use]
 #[allow(clippy::collapsible_if)]
 pub fn pull_enums(
   it: &mut XmlIterator<'_>,
   enums: &mut Enums,
   is_bitmask: bool,
-  mut group: Option<&mut HashSet<String>>,
+  mut group: Option<&mut OrderedGroup>,
 ) -> Option<()> {
   loop {
     match it.next()? {
@@ -85,14 +112,14 @@ pub fn pull_enums(
       EmptyTag { name: "enum", attrs } => {
         let mut name = None;
         let mut value = None;
-        //let mut alias = None;
+        let mut alias = None;
         let mut api = None;
         let mut is_ull = false;
         for (k, v) in AttributeIterator::new(attrs) {
           match k {
             "name" => name = Some(v),
             "value" => value = Some(v),
-            "alias" => (), //alias = Some(v),
+            "alias" => alias = Some(v.to_owned()),
             "comment" => (),
             "type" => is_ull = v == "ull",
             "api" => api = Some(v.to_owned()),
@@ -122,9 +149,9 @@ pub fn pull_enums(
             EnumValue::Enum(u32::from_str_radix(&value, 10).unwrap())
           }
         };
-        let key = EnumKey { name, api: api.clone() };
-        if enums.0.contains_key(&key) {
-          let old = *enums.0.get(&key).unwrap();
+        let key = EnumKey { name: name.clone(), api: api.clone() };
+        if enums.map.contains_key(&key) {
+          let old = *enums.map.get(&key).unwrap();
           let new = val;
           if old != new {
             panic!(
@@ -136,27 +163,32 @@ pub fn pull_enums(
           if let Some(group) = group.as_mut() {
             group.insert(key.name.clone());
           }
-          enums.0.insert(key, val);
+          enums.map.insert(key, val);
+        }
+        // `alias` names the canonical enum that `name` stands in for (eg: an
+        // EXT-suffixed name aliasing its promoted core equivalent). Register
+        // the canonical name too, in case no `<enum>` tag defines it
+        // directly, and record the relationship so emission can reference
+        // the canonical constant instead of duplicating its literal value.
+        if let Some(alias) = alias {
+          enums.aliases.insert(name.clone(), alias.clone());
+          let canonical_key = EnumKey { name: alias, api: api.clone() };
+          if enums.map.contains_key(&canonical_key) {
+            let old = *enums.map.get(&canonical_key).unwrap();
+            let new = val;
+            if old != new {
+              panic!(
+                "key overwrite: key: {:?}, old: {:?}, new: {:?}",
+                canonical_key, old, new
+              );
+            }
+          } else {
+            if let Some(group) = group.as_mut() {
+              group.insert(canonical_key.name.clone());
+            }
+            enums.map.insert(canonical_key, val);
+          }
         }
-        // if let Some(alias) = alias {
-        //   let name = alias.to_owned();
-        //   let key = EnumKey { name, api: api.clone() };
-        //   if enums.0.contains_key(&key) {
-        //     let old = *enums.0.get(&key).unwrap();
-        //     let new = val;
-        //     if old != new {
-        //       panic!(
-        //         "key overwrite: key: {:?}, old: {:?}, new: {:?}",
-        //         key, old, new
-        //       );
-        //     }
-        //   } else {
-        //     if let Some(group) = group.as_mut() {
-        //       group.insert(key.name.clone());
-        //     }
-        //     enums.0.insert(key, val);
-        //   }
-        // }
       }
       EmptyTag { name: "unused", attrs } => {
         // TODO: We should check if the `unused` tag is somehow used despite the
@@ -167,3 +199,452 @@ pub fn pull_enums(
     }
   }
 }
+
+/// Turns a `GL_`-prefixed registry name into a valid Rust identifier suffix:
+/// strips the `GL_` prefix and, if what's left would start with a digit
+/// (eg: `GL_2_BYTES`, `GL_4_BYTES`, `GL_1PASS_EXT`), prepends an underscore
+/// so it's still a legal identifier.
+fn ident_suffix(name: &str) -> std::borrow::Cow<'_, str> {
+  let suffix = &name[3..];
+  if suffix.starts_with(|c: char| c.is_ascii_digit()) {
+    std::borrow::Cow::Owned(format!("_{suffix}"))
+  } else {
+    std::borrow::Cow::Borrowed(suffix)
+  }
+}
+
+/// Displays a whole bitmask group as a typed `#[repr(transparent)]` newtype
+/// instead of a pile of loose `pub const` bitfields.
+///
+/// The generated type wraps the underlying `GLbitfield`, carries one
+/// associated constant per member, and implements the usual bitwise
+/// operators plus `contains`/`empty`/`all`/`Debug`. This follows the shape of
+/// the `encap_enum` crate: a struct around the integer, with variants
+/// exposed as constants rather than as separate enum cases.
+///
+/// The flat `pub const` constants for the same members are still emitted
+/// behind the `flat-enum-constants` feature, for callers that haven't moved
+/// to the typed wrapper yet.
+#[derive(Debug, Clone)]
+pub struct BitflagGroupDisplay<'a> {
+  /// Name of the group (eg: "TextureMagFilter"); the emitted type is named
+  /// `{group_name}Bits`.
+  pub group_name: &'a str,
+  /// The full enum map, used to look up each member's value.
+  pub enums: &'a Enums,
+  /// Names of the group's members, as collected by [`pull_enums`].
+  pub members: &'a OrderedGroup,
+}
+impl core::fmt::Display for BitflagGroupDisplay<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let ty_name = format!("{}Bits", self.group_name);
+    let entries: Vec<(&EnumKey, u32)> = self
+      .members
+      .0
+      .iter()
+      .map(|name| {
+        let key = EnumKey { name: (*name).clone(), api: None };
+        let value = self.enums.map.get(&key).unwrap_or_else(|| {
+          panic!("bitmask group {:?} member {:?} not found", self.group_name, key)
+        });
+        let mask = match value {
+          EnumValue::Bitmask(mask) => *mask,
+          other => panic!("group member {:?} is not a bitmask: {:?}", key, other),
+        };
+        (self.enums.map.get_key_value(&key).unwrap().0, mask)
+      })
+      .collect();
+    let all: u32 = entries.iter().fold(0, |acc, (_, mask)| acc | mask);
+
+    writeln!(f, "#[repr(transparent)]")?;
+    writeln!(f, "#[derive(Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(f, "pub struct {ty}(pub GLbitfield);", ty = ty_name)?;
+    writeln!(f, "impl {ty} {{", ty = ty_name)?;
+    for (key, mask) in &entries {
+      writeln!(
+        f,
+        "  pub const {name}: Self = Self(0x{mask:08X});",
+        name = ident_suffix(&key.name),
+        mask = mask
+      )?;
+    }
+    writeln!(f, "  /// The empty set of flags.")?;
+    writeln!(f, "  pub const fn empty() -> Self {{ Self(0) }}")?;
+    writeln!(f, "  /// The set containing every known flag in this group.")?;
+    writeln!(f, "  pub const fn all() -> Self {{ Self(0x{all:08X}) }}", all = all)?;
+    writeln!(f, "  /// Returns `true` if `self` contains every flag set in `other`.")?;
+    writeln!(
+      f,
+      "  pub const fn contains(self, other: Self) -> bool {{ (self.0 & other.0) == other.0 }}"
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(
+      f,
+      "impl core::ops::BitOr for {ty} {{ type Output = Self; fn bitor(self, rhs: Self) -> Self {{ Self(self.0 | rhs.0) }} }}",
+      ty = ty_name
+    )?;
+    writeln!(
+      f,
+      "impl core::ops::BitAnd for {ty} {{ type Output = Self; fn bitand(self, rhs: Self) -> Self {{ Self(self.0 & rhs.0) }} }}",
+      ty = ty_name
+    )?;
+    writeln!(
+      f,
+      "impl core::ops::BitXor for {ty} {{ type Output = Self; fn bitxor(self, rhs: Self) -> Self {{ Self(self.0 ^ rhs.0) }} }}",
+      ty = ty_name
+    )?;
+    writeln!(
+      f,
+      "impl core::ops::Sub for {ty} {{ type Output = Self; fn sub(self, rhs: Self) -> Self {{ Self(self.0 & !rhs.0) }} }}",
+      ty = ty_name
+    )?;
+    writeln!(
+      f,
+      "impl core::ops::Not for {ty} {{ type Output = Self; fn not(self) -> Self {{ Self(!self.0 & Self::all().0) }} }}",
+      ty = ty_name
+    )?;
+    writeln!(f, "impl core::fmt::Debug for {ty} {{", ty = ty_name)?;
+    writeln!(f, "  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{")?;
+    writeln!(f, "    let mut rest = self.0;")?;
+    writeln!(f, "    let mut first = true;")?;
+    // A zero-valued member is vacuously "contained" by every mask (`rest &
+    // 0 == 0` always holds), so it would print unconditionally and never
+    // actually consume any bits from `rest`. Skip it here; `all()`/`empty()`
+    // already cover that case explicitly.
+    for (key, _) in entries.iter().filter(|(_, mask)| *mask != 0) {
+      let name = ident_suffix(&key.name);
+      writeln!(
+        f,
+        "    if rest & Self::{name}.0 == Self::{name}.0 {{ if !first {{ write!(f, \" | \")?; }} write!(f, \"{name}\")?; rest &= !Self::{name}.0; first = false; }}",
+      )?;
+    }
+    writeln!(f, "    if rest != 0 {{ if !first {{ write!(f, \" | \")?; }} write!(f, \"0x{{:08X}}\", rest)?; }}")?;
+    writeln!(f, "    Ok(())")?;
+    writeln!(f, "  }}")?;
+    writeln!(f, "}}")?;
+    for (key, mask) in &entries {
+      writeln!(f, "#[cfg(feature = \"flat-enum-constants\")]")?;
+      writeln!(
+        f,
+        "{}",
+        EnumDisplay { key, value: &EnumValue::Bitmask(*mask) }
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// Builds the compile-time perfect-hash tables used to turn a raw enum value
+/// back into its canonical `GL_`-prefixed name, for things like pretty-
+/// printing an unrecognized GL error code.
+///
+/// `GLenum`, `GLbitfield` and `u64`-tagged ("ull") values don't share a key
+/// space, so each gets its own [`phf_codegen::Map`]. API-specific entries
+/// (`api.is_some()`) are skipped, since the same value can mean different
+/// things depending on the API and a reverse lookup would be ambiguous.
+/// Aliased names that collapse onto one numeric value keep the
+/// alphabetically first name as canonical and carry the rest as `alternates`.
+#[must_use]
+pub fn build_enum_name_tables(enums: &Enums) -> EnumNameTables<'_> {
+  let mut by_enum: HashMap<u32, Vec<&str>> = HashMap::new();
+  let mut by_bitmask: HashMap<u32, Vec<&str>> = HashMap::new();
+  let mut by_ull: HashMap<u64, Vec<&str>> = HashMap::new();
+
+  // Sort by name first so which alias becomes "canonical" is deterministic
+  // across runs, rather than depending on `HashMap` iteration order.
+  let mut entries: Vec<_> = enums.map.iter().collect();
+  entries.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+  for (key, value) in entries {
+    if key.api.is_some() {
+      continue;
+    }
+    match value {
+      EnumValue::Enum(num) => by_enum.entry(*num).or_default().push(&key.name),
+      EnumValue::Bitmask(mask) => by_bitmask.entry(*mask).or_default().push(&key.name),
+      EnumValue::ULL(ull) => by_ull.entry(*ull).or_default().push(&key.name),
+    }
+  }
+  EnumNameTables { by_enum, by_bitmask, by_ull }
+}
+
+/// Intermediate form of the reverse-lookup tables; call [`EnumNameTables::write`]
+/// to turn it into generated Rust source backed by `phf`.
+#[derive(Debug, Default)]
+pub struct EnumNameTables<'a> {
+  by_enum: HashMap<u32, Vec<&'a str>>,
+  by_bitmask: HashMap<u32, Vec<&'a str>>,
+  by_ull: HashMap<u64, Vec<&'a str>>,
+}
+impl EnumNameTables<'_> {
+  /// Writes the three generated `phf::Map` statics plus the public
+  /// `enum_name`/`bitmask_name`/`ull_name` lookup functions.
+  pub fn write(&self, out: &mut impl core::fmt::Write) -> core::fmt::Result {
+    Self::write_table(out, "GL_ENUM_NAMES", "GLenum", &self.by_enum)?;
+    Self::write_table(out, "GL_BITMASK_NAMES", "GLbitfield", &self.by_bitmask)?;
+    Self::write_table(out, "GL_ULL_NAMES", "u64", &self.by_ull)?;
+    // Alternates (other names aliasing the same value) are kept in the
+    // tables for anyone reading the generated source, but the public
+    // lookups only surface the canonical name.
+    writeln!(
+      out,
+      "pub fn enum_name(value: GLenum) -> Option<&'static str> {{ GL_ENUM_NAMES.get(&value).map(|&(name, _)| name) }}"
+    )?;
+    writeln!(
+      out,
+      "pub fn bitmask_name(value: GLbitfield) -> Option<&'static str> {{ GL_BITMASK_NAMES.get(&value).map(|&(name, _)| name) }}"
+    )?;
+    writeln!(
+      out,
+      "pub fn ull_name(value: u64) -> Option<&'static str> {{ GL_ULL_NAMES.get(&value).map(|&(name, _)| name) }}"
+    )
+  }
+
+  fn write_table<K>(
+    out: &mut impl core::fmt::Write,
+    ident: &str,
+    ty: &str,
+    table: &HashMap<K, Vec<&str>>,
+  ) -> core::fmt::Result
+  where
+    K: Copy + Ord + core::hash::Hash + phf_shared::PhfHash + phf_shared::FmtConst + 'static,
+  {
+    let mut keys: Vec<K> = table.keys().copied().collect();
+    keys.sort();
+    let mut map = phf_codegen::Map::new();
+    let mut values = Vec::with_capacity(keys.len());
+    for key in &keys {
+      let names = &table[key];
+      let (canonical, alternates) = names.split_first().expect("non-empty name list");
+      values.push(format!("(\"{canonical}\", &{alternates:?})"));
+    }
+    for (key, value) in keys.iter().zip(values.iter()) {
+      map.entry(*key, value);
+    }
+    let built = map.build();
+    writeln!(out, "static {ident}: phf::Map<{ty}, (&str, &[&str])> = \n{built};")
+  }
+}
+
+/// Displays a resolved `alias="..."` relationship as a reference to its
+/// canonical constant (`pub const GL_ALIAS: T = GL_CANONICAL;`) instead of
+/// duplicating the literal value. `T` is whichever of `GLenum`/
+/// `GLbitfield`/`u64` the resolved [`EnumValue`] actually is, and both sides
+/// keep their full `GL_`-prefixed names, matching how [`EnumDisplay`] names
+/// the constants it emits.
+///
+/// Panics if the alias and its canonical target don't carry equal values,
+/// reusing the same "key overwrite" consistency check [`pull_enums`] applies
+/// when it encounters the same name twice.
+#[must_use]
+pub fn display_alias<'a>(enums: &'a Enums, alias: &'a str) -> AliasDisplay<'a> {
+  let canonical = enums
+    .aliases
+    .get(alias)
+    .unwrap_or_else(|| panic!("{:?} is not a registered alias", alias));
+  // Names aren't always registered under `api: None` — some only exist
+  // under a specific api (eg: "gles2"), same as any other enum `pull_enums`
+  // collects. Look the name up regardless of api rather than assuming one.
+  let alias_value = find_value_by_name(enums, alias);
+  let canonical_value = find_value_by_name(enums, canonical);
+  if let (Some(a), Some(c)) = (alias_value, canonical_value) {
+    if a != c {
+      panic!(
+        "key overwrite: key: {:?}, old: {:?}, new: {:?}",
+        EnumKey { name: alias.to_owned(), api: None },
+        c,
+        a
+      );
+    }
+  }
+  let value = alias_value.or(canonical_value).unwrap_or_else(|| {
+    panic!("neither {:?} nor {:?} is a known enum", alias, canonical)
+  });
+  let ty = match value {
+    EnumValue::Enum(_) => "GLenum",
+    EnumValue::Bitmask(_) => "GLbitfield",
+    EnumValue::ULL(_) => "u64",
+  };
+  AliasDisplay { alias, canonical, ty }
+}
+
+/// Finds any [`EnumValue`] registered under `name`, preferring the
+/// api-less (`api: None`) definition when one exists, since that's the
+/// common case and the one most other names resolve to.
+fn find_value_by_name<'a>(enums: &'a Enums, name: &str) -> Option<&'a EnumValue> {
+  enums
+    .map
+    .get(&EnumKey { name: name.to_owned(), api: None })
+    .or_else(|| enums.map.iter().find(|(key, _)| key.name == name).map(|(_, v)| v))
+}
+
+/// See [`display_alias`].
+#[derive(Debug, Clone)]
+pub struct AliasDisplay<'a> {
+  alias: &'a str,
+  canonical: &'a str,
+  ty: &'static str,
+}
+impl core::fmt::Display for AliasDisplay<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "pub const {alias}: {ty} = {canonical};",
+      alias = self.alias,
+      ty = self.ty,
+      canonical = self.canonical
+    )
+  }
+}
+
+/// Displays every variant of a given enum name, gating each api-specific
+/// definition behind a `cfg(feature = "...")` block.
+///
+/// Most names have a single, api-less definition and generate a plain
+/// `pub const` as before. A handful of names (eg: some GLES-vs-GL
+/// differences) resolve to a different value per API; for those,
+/// [`pull_enums`] has inserted one [`EnumValue`] per [`EnumKey::api`], and
+/// emitting them all as bare `pub const` lines for the same name would
+/// collide. Instead each api-specific variant is gated behind a feature
+/// named after its api string, and the api-less variant (if any) is gated
+/// behind the negation of every other api's feature, so it acts as the
+/// default when no api-specific feature is enabled.
+#[must_use]
+pub fn display_enum_variants<'a>(
+  enums: &'a Enums,
+  name: &'a str,
+) -> ApiVariantsDisplay<'a> {
+  let mut variants: Vec<(&'a EnumKey, &'a EnumValue)> =
+    enums.map.iter().filter(|(key, _)| key.name == name).collect();
+  variants.sort_by(|a, b| a.0.api.cmp(&b.0.api));
+  ApiVariantsDisplay { variants }
+}
+
+/// See [`display_enum_variants`].
+#[derive(Debug, Clone)]
+pub struct ApiVariantsDisplay<'a> {
+  variants: Vec<(&'a EnumKey, &'a EnumValue)>,
+}
+impl core::fmt::Display for ApiVariantsDisplay<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    // The common case: a single, api-less definition. Emit it plainly.
+    if self.variants.len() <= 1 {
+      if let Some((key, value)) = self.variants.first() {
+        return write!(f, "{}", EnumDisplay { key, value });
+      }
+      return Ok(());
+    }
+    let apis: Vec<&str> = self
+      .variants
+      .iter()
+      .filter_map(|(key, _)| key.api.as_deref())
+      .collect();
+    for (index, (key, value)) in self.variants.iter().enumerate() {
+      if index > 0 {
+        writeln!(f)?;
+      }
+      match &key.api {
+        Some(api) => writeln!(f, "#[cfg(feature = {api:?})]")?,
+        None => {
+          let negated = apis
+            .iter()
+            .map(|api| format!("feature = {api:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+          writeln!(f, "#[cfg(not(any({negated})))]")?;
+        }
+      }
+      write!(f, "{}", EnumDisplay { key, value })?;
+    }
+    Ok(())
+  }
+}
+
+/// Displays a dense, iterable `{group}Map<V>` container for a group's
+/// members, in the style of the `enum-map` crate.
+///
+/// Unlike a `HashMap<GLenum, V>`, the generated type is a fixed-size array
+/// wrapper (`struct {group}Map<V> { data: [V; N] }`) indexed by each
+/// member's position in the group. Since the backing storage is a plain
+/// array, the whole structure is `Copy` when `V` is and const-constructible,
+/// with O(1) lookups and no hashing. Needs the group's member order, which
+/// is why [`pull_enums`] collects groups into an [`OrderedGroup`] rather
+/// than a `HashSet`: array indices are assigned by position, so the order
+/// has to be stable.
+#[derive(Debug, Clone)]
+pub struct EnumMapDisplay<'a> {
+  /// Name of the group (eg: "SamplerParameter"); the emitted type is named
+  /// `{group_name}Map`, and the emitted enum is named `{group_name}`.
+  pub group_name: &'a str,
+  /// Names of the group's members, in the order assigned to array indices.
+  pub members: &'a OrderedGroup,
+}
+impl core::fmt::Display for EnumMapDisplay<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let enum_name = self.group_name;
+    let map_name = format!("{}Map", self.group_name);
+    let len = self.members.0.len();
+
+    writeln!(f, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(f, "pub enum {enum_name} {{")?;
+    for name in &self.members.0 {
+      writeln!(f, "  {name},", name = ident_suffix(name))?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f, "impl {enum_name} {{")?;
+    writeln!(f, "  const VARIANTS: [{enum_name}; {len}] = [")?;
+    for name in &self.members.0 {
+      writeln!(f, "    {enum_name}::{name},", name = ident_suffix(name))?;
+    }
+    writeln!(f, "  ];")?;
+    writeln!(f, "  const fn index(self) -> usize {{")?;
+    writeln!(f, "    match self {{")?;
+    for (index, name) in self.members.0.iter().enumerate() {
+      writeln!(
+        f,
+        "      {enum_name}::{name} => {index},",
+        name = ident_suffix(name)
+      )?;
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f, "  }}")?;
+    writeln!(f, "}}")?;
+
+    writeln!(f, "#[derive(Debug, Clone, Copy)]")?;
+    writeln!(f, "pub struct {map_name}<V> {{ data: [V; {len}] }}")?;
+    writeln!(f, "impl<V> {map_name}<V> {{")?;
+    writeln!(f, "  /// Builds a map by evaluating `f` once per variant.")?;
+    writeln!(
+      f,
+      "  pub fn from_fn(mut f: impl FnMut({enum_name}) -> V) -> Self {{"
+    )?;
+    writeln!(
+      f,
+      "    Self {{ data: {enum_name}::VARIANTS.map(|variant| f(variant)) }}"
+    )?;
+    writeln!(f, "  }}")?;
+    writeln!(f, "  /// Builds a map from values already laid out in variant order.")?;
+    writeln!(
+      f,
+      "  pub const fn from_array(data: [V; {len}]) -> Self {{ Self {{ data }} }}"
+    )?;
+    writeln!(f, "  pub fn iter(&self) -> impl Iterator<Item = ({enum_name}, &V)> {{")?;
+    writeln!(
+      f,
+      "    {enum_name}::VARIANTS.iter().copied().zip(self.data.iter())"
+    )?;
+    writeln!(f, "  }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f, "impl<V> core::ops::Index<{enum_name}> for {map_name}<V> {{")?;
+    writeln!(f, "  type Output = V;")?;
+    writeln!(
+      f,
+      "  fn index(&self, variant: {enum_name}) -> &V {{ &self.data[variant.index()] }}"
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f, "impl<V> core::ops::IndexMut<{enum_name}> for {map_name}<V> {{")?;
+    write!(
+      f,
+      "  fn index_mut(&mut self, variant: {enum_name}) -> &mut V {{ &mut self.data[variant.index()] }}\n}}"
+    )
+  }
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "fetch")]
+use std::fs;
+#[cfg(feature = "fetch")]
+use std::io::{Read, Write};
+#[cfg(feature = "fetch")]
+use std::path::Path;
+
+/// Fetches the upstream Khronos `gl.xml` registry, or reads it from the
+/// given cache path if it's already been fetched once.
+///
+/// * `cache_path`: Where the registry is stored between builds (eg: a path
+///   under `OUT_DIR`, or a directory the caller has configured). If the file
+///   already exists there, it's read from disk and the network is never
+///   touched.
+///
+/// This mirrors the "pull a remote spec once" caching pattern: the first
+/// build downloads and writes the cache, every later build (as long as the
+/// cache file survives) just reads it back. Callers should print
+/// `cargo:rerun-if-changed=<cache_path>` after calling this so an edited or
+/// deleted cache file triggers a rebuild without forcing one on every build.
+///
+/// Only available with the `fetch` feature enabled; without it, callers are
+/// expected to vendor `gl.xml` themselves and read it directly.
+#[cfg(feature = "fetch")]
+#[must_use]
+pub fn fetch_registry(cache_path: &Path) -> Vec<u8> {
+  if let Ok(cached) = fs::read(cache_path) {
+    return cached;
+  }
+  let bytes = ureq::get(REGISTRY_URL)
+    .call()
+    .unwrap_or_else(|e| panic!("failed to fetch {REGISTRY_URL}: {e}"))
+    .into_reader()
+    .bytes()
+    .collect::<Result<Vec<u8>, _>>()
+    .unwrap_or_else(|e| panic!("failed to read {REGISTRY_URL}: {e}"));
+  if let Some(parent) = cache_path.parent() {
+    fs::create_dir_all(parent)
+      .unwrap_or_else(|e| panic!("failed to create {parent:?}: {e}"));
+  }
+  let mut file = fs::File::create(cache_path)
+    .unwrap_or_else(|e| panic!("failed to create {cache_path:?}: {e}"));
+  file
+    .write_all(&bytes)
+    .unwrap_or_else(|e| panic!("failed to write {cache_path:?}: {e}"));
+  bytes
+}
+
+/// The canonical location of the OpenGL registry XML.
+#[cfg(feature = "fetch")]
+const REGISTRY_URL: &str =
+  "https://raw.githubusercontent.com/KhronosGroup/OpenGL-Registry/main/xml/gl.xml";